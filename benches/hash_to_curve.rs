@@ -0,0 +1,24 @@
+//! `criterion` benchmarks for `hash_to_curve`, gated behind the `std` feature (`criterion`
+//! itself needs std). See `src/lib.rs`'s `no_std` doc section for the `Cargo.toml` wiring this
+//! bench target needs (a `criterion` dev-dependency and a `[[bench]]` entry with
+//! `required-features = ["std"]`) — this crate currently has no `Cargo.toml` to add that to, so
+//! this file (like the rest of the crate) has never actually been compiled or run; see that same
+//! doc section's caveat before relying on it.
+
+// `hash_to_curve` below is this crate's own lib name; adjust if the eventual `Cargo.toml` names
+// the package/lib differently.
+use criterion::{criterion_group, criterion_main, Criterion};
+use hash_to_curve::{Bls12381G1Sswu, DomainSeparationTag, HashToCurveXmd};
+
+fn hash_to_curve_xmd_sha256(c: &mut Criterion) {
+    let dst = DomainSeparationTag::new("BLS12381G1_XMD:SHA-256_SSWU_RO_", Some("BENCH"), None, None)
+        .unwrap();
+    let hasher = Bls12381G1Sswu::from(dst);
+
+    c.bench_function("hash_to_curve_xmd::<Sha256>", |b| {
+        b.iter(|| hasher.hash_to_curve_xmd::<sha2::Sha256, &str>("benchmark message").unwrap())
+    });
+}
+
+criterion_group!(benches, hash_to_curve_xmd_sha256);
+criterion_main!(benches);
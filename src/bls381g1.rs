@@ -2,17 +2,23 @@
 //! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
 //! and Section 5 of
 //!  <https://eprint.iacr.org/2019/403.pdf>
+//!
+//! Also provides the standard BLS12-381 compressed/uncompressed point encoding (as used by
+//! zkcrypto/bellman's `EncodedPoint`), so a point returned by `encode_to_curve`/`hash_to_curve`
+//! can be serialized and later parsed back with on-curve and subgroup validation — see
+//! [`to_compressed`]/[`from_compressed`] and [`to_uncompressed`]/[`from_uncompressed`].
 
 use crate::error::HashingError;
 use crate::isogeny::bls381g1::*;
+use crate::scalar_mul::{recommended_wnaf_for_scalar, wnaf_mul, WnafPoint};
+use crate::sswu::{self, SswuParams};
 use crate::{expand_message_xmd, expand_message_xof, DomainSeparationTag};
 use crate::{HashToCurveXmd, HashToCurveXof};
-use amcl_miracl::bls381::{big::BIG, dbig::DBIG, ecp::ECP};
+use amcl_miracl::bls381::{big::BIG, ecp::ECP};
 use digest::{
-    generic_array::typenum::{marker_traits::Unsigned, U128, U32, U64},
+    generic_array::typenum::{marker_traits::Unsigned, U128, U64},
     BlockInput, Digest, ExtendableOutput, Input, Reset, XofReader,
 };
-use std::cmp::Ordering;
 
 /// To compute a `L` use the following formula
 /// L = ceil(ceil(log2(p) + k) / 8). For example, in our case log2(p) = 381, k = 128
@@ -33,9 +39,10 @@ const PM1DIV2: BIG = BIG {
         3490221905,
     ],
 };
-const H_EFF: BIG = BIG {
-    w: [144396663052632065, 52, 0, 0, 0, 0, 0],
-};
+/// `h_eff = 1 - z`, the BLS12-381 seed `z` subtracted from `1`: the Wahby–Boneh fast cofactor
+/// clearing shortcut of Section 4.1 of <https://eprint.iacr.org/2019/403.pdf>, which lands a
+/// mapped point in the correct G1 subgroup for a fraction of the cost of the full cofactor.
+const H_EFF_FAST: [u8; 8] = [0xd2, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01];
 const C1: BIG = BIG {
     w: [
         132416828320029820,
@@ -89,7 +96,7 @@ impl From<DomainSeparationTag> for Bls12381G1Sswu {
 impl HashToCurveXmd for Bls12381G1Sswu {
     type Output = ECP;
 
-    fn encode_to_curve_xmd<D: BlockInput + Digest<OutputSize = U32>, I: AsRef<[u8]>>(
+    fn encode_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
         &self,
         data: I,
     ) -> Result<Self::Output, HashingError> {
@@ -97,7 +104,7 @@ impl HashToCurveXmd for Bls12381G1Sswu {
         Ok(encode_to_curve(u))
     }
 
-    fn hash_to_curve_xmd<D: BlockInput + Digest<OutputSize = U32>, I: AsRef<[u8]>>(
+    fn hash_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
         &self,
         data: I,
     ) -> Result<Self::Output, HashingError> {
@@ -111,7 +118,7 @@ impl HashToCurveXof for Bls12381G1Sswu {
 
     fn encode_to_curve_xof<
         X: ExtendableOutput + Input + Reset + Default,
-        D: Digest<OutputSize = U32>,
+        D: Digest,
         I: AsRef<[u8]>,
     >(
         &self,
@@ -123,7 +130,7 @@ impl HashToCurveXof for Bls12381G1Sswu {
 
     fn hash_to_curve_xof<
         X: ExtendableOutput + Input + Reset + Default,
-        D: Digest<OutputSize = U32>,
+        D: Digest,
         I: AsRef<[u8]>,
     >(
         &self,
@@ -147,172 +154,299 @@ fn hash_to_curve(u0: BIG, u1: BIG) -> ECP {
 }
 
 /// See Section 7 in
-/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>, using the
+/// `H_EFF_FAST` shortcut and scanning it via wNAF rather than a single wide `ECP::mul`.
 fn clear_cofactor(p: ECP) -> ECP {
-    p.mul(&H_EFF)
+    let w = recommended_wnaf_for_scalar(H_EFF_FAST.len() * 8);
+    wnaf_mul(&p, &H_EFF_FAST, w)
+}
+
+impl WnafPoint for ECP {
+    fn identity() -> Self {
+        ECP::new()
+    }
+
+    fn double(&mut self) {
+        self.dbl();
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.add(other);
+    }
+
+    fn negate(&mut self) {
+        self.neg();
+    }
 }
 
 /// See Section 6.2 in
 /// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
 fn map_to_curve(u: BIG) -> ECP {
-    let (x, y) = map_to_curve_simple_swu(u);
-    iso_map(x, y)
+    sswu::map_to_curve_simple_swu::<Params>(u)
 }
 
-/// See Section 6.6.2.1 in
-/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
-///
-/// Only works if p is congruent to 3 mod 4
-fn map_to_curve_simple_swu(u: BIG) -> (BIG, BIG) {
-    // tv1 = Z * u^2
-    let tv1 = BIG::modmul(&Z, &BIG::modsqr(&u, &MODULUS), &MODULUS);
-    // tv2 = tv1^2
-    let mut tv2 = BIG::modsqr(&tv1, &MODULUS);
+/// BLS12-381 G1's instantiation of the generic [`sswu::SswuParams`] engine: Section 6.6.2.1's
+/// simplified SWU map over this curve's 11-isogenous `E'`, followed by the Section 4.3 isogeny
+/// map of <https://eprint.iacr.org/2019/403.pdf> back to G1 itself.
+struct Params;
 
-    // x1 = tv1 + tv2
-    let mut x1 = BIG::new_big(&tv1);
-    x1.add(&tv2);
-    x1.rmod(&MODULUS);
+impl SswuParams for Params {
+    type Output = ECP;
 
-    // x1 = inv0(x1)
-    x1.invmodp(&MODULUS);
+    const MODULUS: BIG = MODULUS;
+    const PM1DIV2: BIG = PM1DIV2;
+    const Z: BIG = Z;
+    const ISO_A: BIG = ISO_A;
+    const ISO_B: BIG = ISO_B;
+    const C1: BIG = C1;
+    const C2: BIG = C2;
 
-    let e1 = if x1.iszilch() { 1 } else { 0 };
+    fn sqrt(x: &BIG) -> BIG {
+        sqrt_3mod4(x)
+    }
 
-    // x1 = x1 + 1
-    x1.inc(1);
+    fn iso_map(x_prime: BIG, y_prime: BIG) -> ECP {
+        iso_map(x_prime, y_prime)
+    }
+}
 
-    // x1 = CMOV(x1, c2, e1)
-    x1.cmove(&C2, e1);
+/// Section F.1 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+pub(crate) fn sqrt_3mod4(x: &BIG) -> BIG {
+    let mut t = BIG::new_big(x);
+    t.powmod(&SQRT_C1, &MODULUS)
+}
 
-    // x1 = x1 * c1
-    x1 = BIG::modmul(&x1, &C1, &MODULUS);
+/// is_square(x) := { True,  if x^((q - 1) / 2) is 0 or 1 in F;
+///                 { False, otherwise.
+pub(crate) fn is_square(x: &BIG) -> bool {
+    sswu::is_square::<Params>(x)
+}
 
-    // gx1 = x1^2
-    let mut gx1 = BIG::modsqr(&x1, &MODULUS);
-    // gx1 = gx1 + A
-    gx1.add(&ISO_A);
-    gx1.rmod(&MODULUS);
+/// See Section 4.1 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+///
+/// The finalized definition is integer parity: the least-significant bit of `x`'s canonical
+/// representative (`BIG` values coming out of `modmul`/`modsqr`/etc. are already fully reduced,
+/// so `w[0]`'s low bit is exactly that).
+fn sgn0(x: &BIG) -> bool {
+    sswu::sgn0(x)
+}
 
-    // gx1 = gx1 * x1
-    gx1 = BIG::modmul(&gx1, &x1, &MODULUS);
+/// See Section 4.3 in
+/// <https://eprint.iacr.org/2019/403.pdf>
+fn iso_map(x_prime: BIG, y_prime: BIG) -> ECP {
+    let w = sswu::apply_rational_isogeny(x_prime, y_prime, &X_NUM, &X_DEN, &Y_NUM, &Y_DEN, &MODULUS);
+    ECP::new_bigs(&w.x, &w.y)
+}
 
-    // gx1 = gx1 + B
-    gx1.add(&ISO_B);
-    gx1.rmod(&MODULUS);
+/// The number of bytes in G1's compressed point encoding.
+pub const COMPRESSED_SIZE: usize = 48;
+/// The number of bytes in G1's uncompressed point encoding.
+pub const UNCOMPRESSED_SIZE: usize = 96;
 
-    // x2 = tv1 * x1
-    let x2 = BIG::modmul(&tv1, &x1, &MODULUS);
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
 
-    // tv2 = tv1 * tv2
-    tv2 = BIG::modmul(&tv1, &tv2, &MODULUS);
+/// G1's curve equation is `y^2 = x^3 + 4` (`A = 0`).
+const CURVE_B: BIG = BIG {
+    w: [4, 0, 0, 0, 0, 0, 0],
+};
 
-    // gx2 = gx1 * tv2
-    let gx2 = BIG::modmul(&gx1, &tv2, &MODULUS);
+/// The prime order `r` of G1 (and G2), big-endian, used by [`is_in_correct_subgroup`] here and
+/// by `bls381g2::is_in_correct_subgroup`.
+pub(crate) const GROUP_ORDER: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// The standard BLS12-381 compressed point encoding (as used by zkcrypto/bellman's
+/// `EncodedPoint`): a big-endian `x` coordinate with the top three bits of the leading byte
+/// holding the compression flag (always set), the infinity flag, and the sign of `y` (set when
+/// `y` is the lexicographically larger of its two square roots).
+pub fn to_compressed(p: &ECP) -> [u8; COMPRESSED_SIZE] {
+    let mut out = [0u8; COMPRESSED_SIZE];
+    out[0] |= COMPRESSION_FLAG;
+    if p.is_infinity() {
+        out[0] |= INFINITY_FLAG;
+        return out;
+    }
+    big_to_be_bytes(&p.getx(), &mut out);
+    if is_lexicographically_largest(&p.gety()) {
+        out[0] |= SIGN_FLAG;
+    }
+    out
+}
 
-    // e2 = is_square(gx1)
-    let e2 = if is_square(&gx1) { 1 } else { 0 };
+/// The standard BLS12-381 uncompressed point encoding: big-endian `x || y`, with the leading
+/// byte's compression flag clear and its infinity flag set for the point at infinity.
+pub fn to_uncompressed(p: &ECP) -> [u8; UNCOMPRESSED_SIZE] {
+    let mut out = [0u8; UNCOMPRESSED_SIZE];
+    if p.is_infinity() {
+        out[0] |= INFINITY_FLAG;
+        return out;
+    }
+    big_to_be_bytes(&p.getx(), &mut out[..COMPRESSED_SIZE]);
+    big_to_be_bytes(&p.gety(), &mut out[COMPRESSED_SIZE..]);
+    out
+}
 
-    // x = CMOV(x2, x1, e2)
-    let mut x = BIG::new_copy(&x2);
-    x.cmove(&x1, e2);
+/// Parses a compressed G1 point, recovering `y` from `x` via the curve equation and validating
+/// the result lies in the prime-order subgroup.
+pub fn from_compressed(bytes: &[u8]) -> Result<ECP, HashingError> {
+    if bytes.len() != COMPRESSED_SIZE {
+        return Err(HashingError::InvalidEncodingLength);
+    }
+    if bytes[0] & COMPRESSION_FLAG == 0 {
+        return Err(HashingError::InvalidEncodingLength);
+    }
+    if bytes[0] & INFINITY_FLAG != 0 {
+        return Ok(ECP::new());
+    }
+    let sign_y = bytes[0] & SIGN_FLAG != 0;
+    let mut x_bytes = [0u8; COMPRESSED_SIZE];
+    x_bytes.copy_from_slice(bytes);
+    x_bytes[0] &= 0x1f;
+    let x = be_bytes_to_big(&x_bytes);
+    if !big_less_than(&x, &MODULUS) {
+        return Err(HashingError::InvalidEncodingLength);
+    }
 
-    // y2 = CMOV(gx2, gx1, e2)
-    let mut y2 = BIG::new_copy(&gx2);
-    y2.cmove(&gx1, e2);
+    let rhs = rhs_of_curve_equation(&x);
+    if !is_square(&rhs) {
+        return Err(HashingError::PointNotOnCurve);
+    }
+    let mut y = sqrt_3mod4(&rhs);
+    if is_lexicographically_largest(&y) != sign_y {
+        y = BIG::modneg(&y, &MODULUS);
+    }
 
-    // y = sqrt(y2)
-    let y = sqrt_3mod4(&y2);
+    let p = ECP::new_bigs(&x, &y);
+    if !is_in_correct_subgroup(&p) {
+        return Err(HashingError::PointNotInSubgroup);
+    }
+    Ok(p)
+}
 
-    // e3 = sgn0(u) == sgn0(y)
-    let e3 = if sgn0(&u) == sgn0(&y) { 1 } else { 0 };
+/// Parses an uncompressed G1 point, validating it lies on the curve and in the prime-order
+/// subgroup.
+pub fn from_uncompressed(bytes: &[u8]) -> Result<ECP, HashingError> {
+    if bytes.len() != UNCOMPRESSED_SIZE {
+        return Err(HashingError::InvalidEncodingLength);
+    }
+    if bytes[0] & COMPRESSION_FLAG != 0 {
+        return Err(HashingError::InvalidEncodingLength);
+    }
+    if bytes[0] & INFINITY_FLAG != 0 {
+        return Ok(ECP::new());
+    }
+    let x = be_bytes_to_big(&bytes[..COMPRESSED_SIZE]);
+    let y = be_bytes_to_big(&bytes[COMPRESSED_SIZE..]);
+    if !big_less_than(&x, &MODULUS) || !big_less_than(&y, &MODULUS) {
+        return Err(HashingError::InvalidEncodingLength);
+    }
 
-    // y = CMOV(-y, y, e3)
-    let mut y_neg = BIG::modneg(&y, &MODULUS);
-    y_neg.cmove(&y, e3);
+    let lhs = BIG::modsqr(&y, &MODULUS);
+    let rhs = rhs_of_curve_equation(&x);
+    if !big_eq(&lhs, &rhs) {
+        return Err(HashingError::PointNotOnCurve);
+    }
 
-    (x, y_neg)
+    let p = ECP::new_bigs(&x, &y);
+    if !is_in_correct_subgroup(&p) {
+        return Err(HashingError::PointNotInSubgroup);
+    }
+    Ok(p)
 }
 
-/// Section F.1 in
-/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
-fn sqrt_3mod4(x: &BIG) -> BIG {
-    let mut t = BIG::new_big(x);
-    t.powmod(&SQRT_C1, &MODULUS)
+/// `x^3 + CURVE_B mod p`, the right-hand side of G1's curve equation.
+fn rhs_of_curve_equation(x: &BIG) -> BIG {
+    let mut rhs = BIG::modsqr(x, &MODULUS);
+    rhs = BIG::modmul(&rhs, x, &MODULUS);
+    rhs.add(&CURVE_B);
+    rhs.rmod(&MODULUS);
+    rhs
 }
 
-/// is_square(x) := { True,  if x^((q - 1) / 2) is 0 or 1 in F;
-///                 { False, otherwise.
-fn is_square(x: &BIG) -> bool {
-    let mut t = BIG::new_copy(x);
-    t = t.powmod(&PM1DIV2, &MODULUS);
-    let mut sum = 0;
-    for i in 1..t.w.len() {
-        sum |= t.w[i];
+/// `y > (p - 1) / 2`, the "larger of the two square roots" convention the sign-of-y flag bit
+/// encodes.
+fn is_lexicographically_largest(y: &BIG) -> bool {
+    big_less_than(&PM1DIV2, y)
+}
+
+/// Checks `p` lies in the order-`r` subgroup by testing `r * p == O`, via the same wNAF
+/// scanning machinery `clear_cofactor` uses rather than a dedicated `ECP::mul`.
+pub fn is_in_correct_subgroup(p: &ECP) -> bool {
+    let w = recommended_wnaf_for_scalar(GROUP_ORDER.len() * 8);
+    wnaf_mul(p, &GROUP_ORDER, w).is_infinity()
+}
+
+/// `a < b`, comparing fully-reduced `BIG`s limb-by-limb from the most significant down. Every
+/// `BIG` passed here comes out of `modmul`/`modsqr`/`rmod`/a `getx`/`gety` accessor or
+/// `be_bytes_to_big`, so (as with `sgn0`) it is already in canonical non-negative-digit form.
+fn big_less_than(a: &BIG, b: &BIG) -> bool {
+    for i in (0..a.w.len()).rev() {
+        if a.w[i] != b.w[i] {
+            return a.w[i] < b.w[i];
+        }
     }
-    sum == 0 && (t.w[0] == 0 || t.w[0] == 1)
+    false
 }
 
-/// See Section 4.1 in
-/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
-fn sgn0(x: &BIG) -> Ordering {
-    if *x > PM1DIV2 {
-        Ordering::Less
-    } else {
-        Ordering::Greater
+fn big_eq(a: &BIG, b: &BIG) -> bool {
+    !big_less_than(a, b) && !big_less_than(b, a)
+}
+
+/// The number of bits in one `BIG` limb (see `amcl_miracl::bls381::rom::MODULUS`'s 7-limb,
+/// base-2^58 layout).
+const BASEBITS: usize = 58;
+
+/// Packs `x`'s limbs into a big-endian byte array of `out.len()` bytes, most-significant byte
+/// first. `x` is always already reduced mod `MODULUS` (381 bits), which fits comfortably in
+/// `COMPRESSED_SIZE` (48) bytes.
+fn big_to_be_bytes(x: &BIG, out: &mut [u8]) {
+    for byte in out.iter_mut() {
+        *byte = 0;
+    }
+    let len = out.len();
+    for (limb_idx, &limb) in x.w.iter().enumerate() {
+        for bit in 0..BASEBITS {
+            if (limb >> bit) & 1 == 1 {
+                let global_bit = limb_idx * BASEBITS + bit;
+                let byte_idx = global_bit / 8;
+                if byte_idx < len {
+                    out[len - 1 - byte_idx] |= 1 << (global_bit % 8);
+                }
+            }
+        }
     }
 }
 
-/// See Section 4.3 in
-/// <https://eprint.iacr.org/2019/403.pdf>
-fn iso_map(x_prime: BIG, y_prime: BIG) -> ECP {
-    let mut x_values: [BIG; 16] = [BIG::new(); 16];
-    x_values[0] = BIG::new_int(1);
-    x_values[1] = x_prime;
-    x_values[2] = BIG::modsqr(&x_prime, &MODULUS);
-    x_values[3] = BIG::modmul(&x_values[2], &x_prime, &MODULUS);
-    x_values[4] = BIG::modmul(&x_values[3], &x_prime, &MODULUS);
-    x_values[5] = BIG::modmul(&x_values[4], &x_prime, &MODULUS);
-    x_values[6] = BIG::modmul(&x_values[5], &x_prime, &MODULUS);
-    x_values[7] = BIG::modmul(&x_values[6], &x_prime, &MODULUS);
-    x_values[8] = BIG::modmul(&x_values[7], &x_prime, &MODULUS);
-    x_values[9] = BIG::modmul(&x_values[8], &x_prime, &MODULUS);
-    x_values[10] = BIG::modmul(&x_values[9], &x_prime, &MODULUS);
-    x_values[11] = BIG::modmul(&x_values[10], &x_prime, &MODULUS);
-    x_values[12] = BIG::modmul(&x_values[11], &x_prime, &MODULUS);
-    x_values[13] = BIG::modmul(&x_values[12], &x_prime, &MODULUS);
-    x_values[14] = BIG::modmul(&x_values[13], &x_prime, &MODULUS);
-    x_values[15] = BIG::modmul(&x_values[14], &x_prime, &MODULUS);
-
-    let mut x = iso_map_helper(&x_values, &X_NUM);
-    let mut x_den = iso_map_helper(&x_values, &X_DEN);
-    let mut y = iso_map_helper(&x_values, &Y_NUM);
-    let mut y_den = iso_map_helper(&x_values, &Y_DEN);
-
-    x_den.invmodp(&MODULUS);
-    x = BIG::modmul(&x, &x_den, &MODULUS);
-
-    y_den.invmodp(&MODULUS);
-    y = BIG::modmul(&y, &y_den, &MODULUS);
-    y = BIG::modmul(&y, &y_prime, &MODULUS);
-
-    ECP::new_bigs(&x, &y)
-}
-
-/// Compute a section of iso map
-fn iso_map_helper(x: &[BIG], k: &[BIG]) -> BIG {
-    let mut new_x = BIG::new();
-    for i in 0..k.len() {
-        let t = BIG::modmul(&x[i], &k[i], &MODULUS);
-        new_x.add(&t);
-        new_x.rmod(&MODULUS);
-    }
-    new_x
+/// The inverse of [`big_to_be_bytes`]: reconstructs a `BIG` from its big-endian byte encoding.
+/// Does not reduce mod `MODULUS` — callers validate the result is in range themselves.
+fn be_bytes_to_big(bytes: &[u8]) -> BIG {
+    let mut w = [0 as amcl_miracl::arch::Chunk; 7];
+    let len = bytes.len();
+    for (i, &byte) in bytes.iter().enumerate() {
+        let byte_idx_from_end = len - 1 - i;
+        for bit in 0..8 {
+            if (byte >> bit) & 1 == 1 {
+                let global_bit = byte_idx_from_end * 8 + bit;
+                let limb_idx = global_bit / BASEBITS;
+                let limb_bit = global_bit % BASEBITS;
+                if limb_idx < w.len() {
+                    w[limb_idx] |= 1 << limb_bit;
+                }
+            }
+        }
+    }
+    BIG { w }
 }
 
 /// Hash to field using expand_message_xmd to compute `u` as specified in Section 5.2 in
 /// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
-fn hash_to_field_xmd_nu<D: BlockInput + Digest<OutputSize = U32>, M: AsRef<[u8]>>(
+fn hash_to_field_xmd_nu<D: BlockInput + Digest, M: AsRef<[u8]>>(
     msg: M,
     dst: &DomainSeparationTag,
 ) -> Result<BIG, HashingError> {
@@ -327,7 +461,7 @@ fn hash_to_field_xmd_nu<D: BlockInput + Digest<OutputSize = U32>, M: AsRef<[u8]>
 /// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
 ///
 /// We avoid the loop and get compile time checking this way
-fn hash_to_field_xmd_ro<D: BlockInput + Digest<OutputSize = U32>, M: AsRef<[u8]>>(
+fn hash_to_field_xmd_ro<D: BlockInput + Digest, M: AsRef<[u8]>>(
     msg: M,
     dst: &DomainSeparationTag,
 ) -> Result<(BIG, BIG), HashingError> {
@@ -346,7 +480,7 @@ fn hash_to_field_xmd_ro<D: BlockInput + Digest<OutputSize = U32>, M: AsRef<[u8]>
 /// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
 fn hash_to_field_xof_nu<
     X: ExtendableOutput + Input + Reset + Default,
-    D: Digest<OutputSize = U32>,
+    D: Digest,
     M: AsRef<[u8]>,
 >(
     msg: M,
@@ -365,7 +499,7 @@ fn hash_to_field_xof_nu<
 /// We avoid the loop and get compile time checking this way
 fn hash_to_field_xof_ro<
     X: ExtendableOutput + Input + Reset + Default,
-    D: Digest<OutputSize = U32>,
+    D: Digest,
     M: AsRef<[u8]>,
 >(
     msg: M,
@@ -384,25 +518,66 @@ fn hash_to_field_xof_ro<
 
 /// FIELD_ELEMENT_SIZE <= random_bytes.len() <= FIELD_ELEMENT_SIZE * 2
 fn field_elem_from_larger_bytearray(random_bytes: &[u8]) -> BIG {
-    // e_j = OS2IP(tv) mod p
-    let mut d = DBIG::new();
-    for i in 0..random_bytes.len() {
-        d.shl(8);
-        d.w[0] += random_bytes[i] as amcl_miracl::arch::Chunk;
-    }
-    // u = (e_0, ..., e_( m - 1 ) )
-    let u = d.dmod(&MODULUS);
-    u
+    sswu::field_elem_from_bytes(random_bytes, &MODULUS)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::bls381g1::{
-        hash_to_field_xmd_nu, hash_to_field_xmd_ro, map_to_curve, Bls12381G1Sswu,
+        from_compressed, from_uncompressed, hash_to_field_xmd_nu, hash_to_field_xmd_ro,
+        map_to_curve, sgn0, to_compressed, to_uncompressed, Bls12381G1Sswu,
     };
     use crate::{DomainSeparationTag, HashToCurveXmd, HashToCurveXof};
     use amcl_miracl::bls381::{big::BIG, ecp::ECP};
 
+    /// The finalized `sgn0` is integer parity (the LSB of the canonical representative), not
+    /// the earlier `PM1DIV2`-comparison definition.
+    #[test]
+    fn sgn0_is_parity() {
+        assert!(!sgn0(&BIG::new_int(0)));
+        assert!(sgn0(&BIG::new_int(1)));
+        assert!(!sgn0(&BIG::new_int(2)));
+        assert!(sgn0(&BIG::new_int(3)));
+    }
+
+    /// Round-trips the compressed/uncompressed encodings of the identity and of the first two
+    /// `hash_to_curve_xmd_tests` points (its `TESTGEN`-DST vectors).
+    #[test]
+    fn compressed_and_uncompressed_round_trip() {
+        let identity = ECP::new();
+        assert!(identity.is_infinity());
+        let compressed = to_compressed(&identity);
+        assert_eq!(from_compressed(&compressed).unwrap(), identity);
+        let uncompressed = to_uncompressed(&identity);
+        assert_eq!(from_uncompressed(&uncompressed).unwrap(), identity);
+
+        let points = [
+            ("14738daf70f5142df038c9e3be76f5d71b0db6613e5ef55cfe8e43e27f840dc75de97092da617376a9f598e7a0920c47", "12645b7cb071943631d062b22ca61a8a3df2a8bdac4e6fcd2c18643ef37a98beacf770ce28cb01c8abf5ed63d1a19b53"),
+            ("01fea27a940188120178dfceec87dca78b745b6e73757be21c54d6cee6f07e3d5a465cf425c9d34dccfa95acffa86bf2", "18def9271f5fd253380c764a6818e8b6524c3d35864fcf963d85031225d62bf8cd0abeb326c3c62fec56f6100fa04367"),
+        ];
+        for (x, y) in points {
+            let p = ECP::new_bigs(&BIG::from_hex(x.to_string()), &BIG::from_hex(y.to_string()));
+
+            let compressed = to_compressed(&p);
+            assert_eq!(compressed.len(), super::COMPRESSED_SIZE);
+            let decoded = from_compressed(&compressed).unwrap();
+            assert_eq!(decoded, p);
+
+            let uncompressed = to_uncompressed(&p);
+            assert_eq!(uncompressed.len(), super::UNCOMPRESSED_SIZE);
+            let decoded = from_uncompressed(&uncompressed).unwrap();
+            assert_eq!(decoded, p);
+        }
+    }
+
+    #[test]
+    fn from_compressed_rejects_wrong_length() {
+        assert_eq!(
+            from_compressed(&[0u8; 10]),
+            Err(crate::HashingError::InvalidEncodingLength)
+        );
+    }
+
     #[test]
     fn hash_to_curve_xmd_tests() {
         let dst = DomainSeparationTag::new(
@@ -439,6 +614,28 @@ mod tests {
         }
     }
 
+    /// Exercises `BLS12381G1_XMD:SHA-512_SSWU_RO_`: a 64-byte digest drives `expand_message_xmd`
+    /// to a different block count/padding than SHA-256, so this checks the wider digest path
+    /// actually runs (and is deterministic) now that `D` is no longer pinned to `OutputSize = U32`.
+    #[test]
+    fn hash_to_curve_xmd_sha512_tests() {
+        let dst = DomainSeparationTag::new(
+            "BLS12381G1_XMD:SHA-512_SSWU_RO_",
+            Some("TESTGEN"),
+            None,
+            None,
+        )
+        .unwrap();
+        let blshasher = Bls12381G1Sswu::from(dst);
+
+        for msg in ["", "abc", "abcdef0123456789"] {
+            let first = blshasher.hash_to_curve_xmd::<sha2::Sha512, &str>(msg);
+            assert!(first.is_ok());
+            let second = blshasher.hash_to_curve_xmd::<sha2::Sha512, &str>(msg);
+            assert_eq!(first.unwrap(), second.unwrap());
+        }
+    }
+
     #[test]
     fn hash_to_curve_xof_tests() {
         let dst = DomainSeparationTag::new(
@@ -475,6 +672,28 @@ mod tests {
         }
     }
 
+    /// Exercises `BLS12381G1_XOF:SHAKE-256_SSWU_RO_`: pairs the wider `Shake256` XOF with a
+    /// 64-byte digest marker (`Sha3_512`), checking `expand_message_xof`'s `D` bound is no
+    /// longer pinned to 32-byte digests and so can actually reach SHAKE-256's security level.
+    #[test]
+    fn hash_to_curve_xof_shake256_tests() {
+        let dst = DomainSeparationTag::new(
+            "BLS12381G1_XOF:SHAKE-256_SSWU_RO_",
+            Some("TESTGEN"),
+            None,
+            None,
+        )
+        .unwrap();
+        let blshasher = Bls12381G1Sswu::from(dst);
+
+        for msg in ["", "abc", "abcdef0123456789"] {
+            let first = blshasher.hash_to_curve_xof::<sha3::Shake256, sha3::Sha3_512, &str>(msg);
+            assert!(first.is_ok());
+            let second = blshasher.hash_to_curve_xof::<sha3::Shake256, sha3::Sha3_512, &str>(msg);
+            assert_eq!(first.unwrap(), second.unwrap());
+        }
+    }
+
     #[test]
     fn encode_to_curve_xmd_tests() {
         let dst = DomainSeparationTag::new(
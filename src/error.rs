@@ -0,0 +1,56 @@
+//! Error types returned by this crate.
+
+use core::fmt;
+
+/// Errors that can occur while expanding a message or hashing to a curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashingError {
+    /// The `ciphersuite_id` supplied to [`crate::DomainSeparationTag::new`] was empty.
+    EmptyDst,
+    /// The requested `len_in_bytes` is out of range (Section 5.3 of the hash-to-curve spec):
+    /// for `expand_message_xmd`, above `255 * b_in_bytes` or 65535; for `expand_message_xof`,
+    /// above 65535 or below the target security level `k` in bits.
+    LenOutOfRange,
+    /// A point encoding was not the length the compressed or uncompressed format requires, its
+    /// compression flag did not match that format, or a coordinate was not below the modulus.
+    InvalidEncodingLength,
+    /// A decoded point's coordinates don't satisfy the curve equation.
+    PointNotOnCurve,
+    /// A decoded point lies on the curve but not in its prime-order subgroup.
+    PointNotInSubgroup,
+    /// [`crate::registry::Suite::from_name`] was given a ciphersuite ID it doesn't recognize.
+    UnknownCiphersuite,
+    /// [`crate::registry::Suite::from_name`] recognized the ciphersuite ID but the mapping it
+    /// names (Shallue–van de Woestijne, Elligator2, ...) isn't implemented by this crate. Also
+    /// returned for the secp256k1 SSWU suite names, which it recognizes but does not hand out.
+    UnsupportedMapToCurve,
+}
+
+impl fmt::Display for HashingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashingError::EmptyDst => write!(f, "domain separation tag must not be empty"),
+            HashingError::LenOutOfRange => {
+                write!(f, "requested output length is out of range for the chosen hash")
+            }
+            HashingError::InvalidEncodingLength => {
+                write!(f, "point encoding is malformed for its format")
+            }
+            HashingError::PointNotOnCurve => write!(f, "decoded point is not on the curve"),
+            HashingError::PointNotInSubgroup => {
+                write!(f, "decoded point is not in the prime-order subgroup")
+            }
+            HashingError::UnknownCiphersuite => {
+                write!(f, "ciphersuite ID is not one this crate implements")
+            }
+            HashingError::UnsupportedMapToCurve => {
+                write!(f, "ciphersuite's map-to-curve function is not implemented")
+            }
+        }
+    }
+}
+
+// `core::error::Error` isn't available on every MSRV this crate supports, so the trait impl is
+// only provided under `std` rather than unconditionally via `core`.
+#[cfg(feature = "std")]
+impl std::error::Error for HashingError {}
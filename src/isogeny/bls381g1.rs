@@ -0,0 +1,106 @@
+//! The 11-isogeny map from `E'1` to BLS12-381 G1 and the simplified SWU parameters used to
+//! reach `E'1`, as described in Section 8.8.1 and Appendix E.1 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>.
+
+use amcl_miracl::bls381::big::BIG;
+
+/// The non-square element used by `map_to_curve_simple_swu` for G1.
+pub const Z: BIG = BIG { w: [11, 0, 0, 0, 0, 0, 0] };
+
+/// The `A` coefficient of the isogenous curve `E'1 : y^2 = x^3 + A'x + B'`.
+pub const ISO_A: BIG = BIG {
+    w: [
+        68723909903010845,
+        61893056659349463,
+        108560269967018377,
+        94029932059124642,
+        200811820887370392,
+        67055163207732864,
+        21260682,
+    ],
+};
+
+/// The `B` coefficient of the isogenous curve `E'1 : y^2 = x^3 + A'x + B'`.
+pub const ISO_B: BIG = BIG {
+    w: [
+        129558657235364832,
+        56390156168112500,
+        5907603866473890,
+        271281666432074471,
+        33001765190244033,
+        25367983822128314,
+        5069408465,
+    ],
+};
+
+/// `x_num` coefficients `k_(1,0) .. k_(1,11)` of the 11-isogeny map.
+pub const X_NUM: [BIG; 12] = [
+    BIG { w: [192553496166681015, 13710346175601579, 267239442063590753, 153838564562422153, 133304882184269886, 261433972884653063, 4731564721] },
+    BIG { w: [181075198886066145, 64117590211104091, 136018869006920520, 132517378918841689, 225765447717863806, 262632288323773162, 388583123] },
+    BIG { w: [6649424019119280, 220552456941905912, 166667024349907288, 129660347053613539, 130897521178182436, 170466337272107569, 3577742811] },
+    BIG { w: [122591578443966561, 124015259816846577, 227606720351319347, 114184518710576844, 218395256206915044, 284601359968338548, 6300791142] },
+    BIG { w: [78053135054575065, 182989297976996116, 195442052924803461, 101411667962503611, 280368379806210969, 28847958947573036, 3918997155] },
+    BIG { w: [77618676612016515, 127028170612229747, 196198497299008008, 141693281358478158, 285881040681778353, 242285082695714822, 5956710992] },
+    BIG { w: [132840957463358852, 41739867667542711, 153561764744833242, 43131909286805539, 255269571977614726, 286281261382486448, 3605882195] },
+    BIG { w: [57234085175675022, 140242923896531943, 27847396080956315, 192701976466070027, 58847504392089061, 30107895666118177, 6367078256] },
+    BIG { w: [104031849664733975, 46580422185035099, 102618023077995770, 122615876017097494, 100133181302520693, 173920685375368397, 2161364767] },
+    BIG { w: [226323919034453150, 162916523391442527, 93360956930259505, 144370487146159500, 98256272358190795, 212770828906073556, 6068238561] },
+    BIG { w: [173352572722477947, 206540935869725490, 231372684603584361, 249837500256441387, 131262288223698380, 176627715178642178, 4347517447] },
+    BIG { w: [128557298454024745, 200093719814096874, 284215117639748644, 142016361557692162, 252744661594134429, 254879560754505096, 1846067784] },
+];
+
+/// `x_den` coefficients `k_(2,0) .. k_(2,9)`; the denominator is monic, so the implicit `x'^10`
+/// term is represented here by the trailing `1`.
+pub const X_DEN: [BIG; 10] = [
+    BIG { w: [89220945865218844, 159209762809937446, 37744020306668373, 85160942843359830, 237516477924759023, 234123870761433483, 2359874888] },
+    BIG { w: [128395946138221567, 192002334703336057, 10564951881380157, 227488689502028955, 187151405540196840, 204182680058534980, 4922123742] },
+    BIG { w: [54669618064145945, 68721259772360511, 63062565326605674, 125372664621036185, 126086054060984641, 137580342189881738, 2996187109] },
+    BIG { w: [220087677247433944, 123084896737259780, 183165618453546135, 71031498790490930, 251147058702649380, 99845275221142523, 874862618] },
+    BIG { w: [260427417354674718, 46982165365131092, 22099338422233533, 100913733578929581, 235286282048102343, 38919593520777047, 5277357600] },
+    BIG { w: [12940850806064293, 14798262718678128, 274423033559709504, 263243367565240590, 217587608383477557, 88270916622894047, 3879034766] },
+    BIG { w: [10542432880446010, 66662169205807931, 32376403771641511, 173033780656027832, 279321476372827991, 25417986962588440, 1999416015] },
+    BIG { w: [141821176212904542, 103110170082063556, 152242331935146234, 218546806540232283, 32878703074508643, 241270425859718508, 5544526505] },
+    BIG { w: [249646565258548801, 43246725490596881, 61475840856379448, 33149746653548608, 121535221293154189, 245819878750323407, 2702102378] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+];
+
+/// `y_num` coefficients `k_(3,0) .. k_(3,15)` of the 11-isogeny map.
+pub const Y_NUM: [BIG; 16] = [
+    BIG { w: [1092604763263737, 156737424037627955, 263865770015388294, 144819008362433265, 163005636162129500, 184078703855158097, 6866475018] },
+    BIG { w: [170075097811531956, 115089790669167406, 273481603852422065, 23084660871082833, 124748816249386741, 210253137937808954, 2495179757] },
+    BIG { w: [189799602038617483, 184563748018591362, 44767409286413499, 45949405169972648, 84831671571730153, 191800630011531481, 5596445144] },
+    BIG { w: [148347315203631830, 281685195495454038, 40814673031055819, 163544945428635324, 194509459535215058, 170669971615053424, 2240091986] },
+    BIG { w: [185960506125461577, 111594209757083709, 192652154057815614, 272293286992637409, 279818284308189302, 89983825970654738, 5417184961] },
+    BIG { w: [264906677521132859, 173230183381097264, 187771721348877242, 213088462766793126, 156241906913797840, 14993074290766433, 3434787770] },
+    BIG { w: [276358882393847488, 148703839175860161, 108799512057343611, 129839075877749339, 131715343437411769, 209483249388756811, 4108277753] },
+    BIG { w: [188944352513845582, 23892947966564577, 213681148510982409, 277346689511685167, 288213297084453672, 203742866327724643, 3290168207] },
+    BIG { w: [25295995383076186, 71632768643255362, 284956194691560802, 132115672230548724, 166552785401725450, 234705475983073020, 52040642] },
+    BIG { w: [65419824016664314, 217740195972069037, 255728303927374244, 26410798447822834, 152907300014548244, 127802313242020849, 581758710] },
+    BIG { w: [233006499619641180, 159119269880284277, 132518603020296872, 2519047298093468, 267914065477201779, 210111336675270292, 1354128686] },
+    BIG { w: [85066815099035135, 79636142633739219, 190668003184129071, 28431968580119804, 230867265153102639, 245996799425207210, 4526371490] },
+    BIG { w: [255191146473181269, 45685610048237361, 32939172476850537, 110465758704850421, 94088882160258746, 260129571015816887, 2306215448] },
+    BIG { w: [41731899524398417, 148007101161879818, 39362353289042147, 165191951511685623, 30834837816576807, 8919799096145864, 341379963] },
+    BIG { w: [175530207995291049, 168159240933773230, 125176566589057503, 279576777610415724, 57010060582690055, 69763004835801682, 461744185] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+];
+
+/// `y_den` coefficients `k_(4,0) .. k_(4,14)`; the denominator is monic, so the implicit
+/// `x'^15` term is represented here by the trailing `1`.
+pub const Y_DEN: [BIG; 16] = [
+    BIG { w: [123106913490853338, 212320327284042390, 80511914967726161, 238104084494680786, 120917244153515869, 97682561562633499, 4625755767] },
+    BIG { w: [222276259075412348, 254590563129108722, 135034051117481554, 31585428268897139, 31986763986521309, 16002665819924093, 1762450126] },
+    BIG { w: [182055861118169876, 84170304976554780, 140781737678962316, 88199252984016797, 180319836797061918, 72796452290503557, 3718868512] },
+    BIG { w: [84259449566553754, 258542925886106621, 110874555724196449, 13925162879632024, 221605329363238838, 4782677019561720, 6356422981] },
+    BIG { w: [186673028147998206, 163999025982084708, 132858525295988602, 135772312657519238, 230897755507025523, 239664121023843118, 5323537596] },
+    BIG { w: [267043588111248524, 195922053316460307, 18353347606014349, 194286489436170470, 272193044286500788, 1970055524827611, 665338074] },
+    BIG { w: [267305614992379680, 173935606096211649, 16511817742826199, 74912515707215600, 223186764100289215, 205109780005030402, 5333460687] },
+    BIG { w: [148022766909876246, 211076659981001619, 250284191487894977, 216344688881085847, 89607442462498834, 63078583149063636, 84415541] },
+    BIG { w: [205664315296361849, 165117928423998349, 108900199533324610, 163899102040695593, 14836508868986035, 136969927460141336, 2725963545] },
+    BIG { w: [70237974331758917, 176884697270535624, 104840052436217053, 60912347907885221, 273208512649933104, 125194477164794921, 3493757708] },
+    BIG { w: [141398954681206522, 247945536999991720, 214167416151190489, 177491043363895915, 232611031998722489, 207782343431153117, 6693389531] },
+    BIG { w: [126836861646864873, 187856219746038884, 22951618573347623, 262782230260782123, 229727709591394136, 131429296669233525, 2342493904] },
+    BIG { w: [137484761432100032, 142988065023963911, 58003028047982968, 246842909913942094, 138777055092245642, 85586520518308189, 4346408442] },
+    BIG { w: [95204466449110217, 100640549914212248, 206963927981003189, 254672469525534138, 233169553913824633, 165766291674617124, 6719106735] },
+    BIG { w: [194001787373476246, 172210798565163559, 170793257684928301, 181841658188965747, 153220416579206827, 280936837971236303, 5295204904] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+];
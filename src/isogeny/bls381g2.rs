@@ -0,0 +1,59 @@
+//! The 3-isogeny map from `E'2` to BLS12-381 G2 and the simplified SWU parameters used to
+//! reach `E'2`, as described in Section 8.8.2 and Appendix E.3 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>.
+//!
+//! Every coefficient lives in Fp2 and is stored as a `(c0, c1)` pair of `BIG`s, with
+//! `c0 + c1 * I` the usual Fp2 representation used throughout `bls381g2`.
+
+use amcl_miracl::bls381::big::BIG;
+
+/// The non-square element used by `map_to_curve_simple_swu` for G2: `Z = -(2 + I)`.
+pub const Z: (BIG, BIG) = (
+    BIG { w: [143833713099123369, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] },
+    BIG { w: [143833713099123370, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] },
+);
+
+/// `A' = 240 * I`, the `A` coefficient of the isogenous curve `E'2 : y^2 = x^3 + A'x + B'`.
+pub const ISO_A: (BIG, BIG) = (
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [240, 0, 0, 0, 0, 0, 0] },
+);
+
+/// `B' = 1012 * (1 + I)`, the `B` coefficient of the isogenous curve `E'2 : y^2 = x^3 + A'x + B'`.
+pub const ISO_B: (BIG, BIG) = (
+    BIG { w: [1012, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [1012, 0, 0, 0, 0, 0, 0] },
+);
+
+/// `x_num` coefficients `k_(1,0) .. k_(1,3)` of the 3-isogeny map.
+pub const X_NUM: [(BIG, BIG); 4] = [
+    (BIG { w: [160065436756121558, 112089510869845784, 18643665678620098, 65289997169337046, 164337326564230445, 262208026937056934, 1551209735] }, BIG { w: [160065436756121558, 112089510869845784, 18643665678620098, 65289997169337046, 164337326564230445, 262208026937056934, 1551209735] }),
+    (BIG { w: [0, 0, 0, 0, 0, 0, 0] }, BIG { w: [191965934116652826, 48038156457825609, 55930997035860295, 195869991508011138, 204781603540979591, 210163328507747315, 4653629207] }),
+    (BIG { w: [191965934116652830, 48038156457825609, 55930997035860295, 195869991508011138, 204781603540979591, 210163328507747315, 4653629207] }, BIG { w: [240098155134182285, 168134266304768676, 27965498517930147, 242050183829861441, 246505989846345667, 249196852329729529, 2326814603] }),
+    (BIG { w: [63800994721062609, 160127667327671394, 74574662714480393, 261159988677348184, 80888553953498292, 184140979293092506, 6204838943] }, BIG { w: [0, 0, 0, 0, 0, 0, 0] }),
+];
+
+/// `x_den` coefficients `k_(2,0) .. k_(2,1)`; the denominator is monic, so the implicit `x'^2`
+/// term is represented here by the trailing `(1, 0)`.
+pub const X_DEN: [(BIG, BIG); 3] = [
+    (BIG { w: [0, 0, 0, 0, 0, 0, 0] }, BIG { w: [143833713099123299, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }),
+    (BIG { w: [12, 0, 0, 0, 0, 0, 0] }, BIG { w: [143833713099123359, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }),
+    (BIG { w: [1, 0, 0, 0, 0, 0, 0] }, BIG { w: [0, 0, 0, 0, 0, 0, 0] }),
+];
+
+/// `y_num` coefficients `k_(3,0) .. k_(3,3)` of the 3-isogeny map.
+pub const Y_NUM: [(BIG, BIG); 4] = [
+    (BIG { w: [202599433236829958, 218841289088293380, 260513691589414856, 239396656287569168, 218263029199895973, 192815095697977442, 5687769031] }, BIG { w: [202599433236829958, 218841289088293380, 260513691589414856, 239396656287569168, 218263029199895973, 192815095697977442, 5687769031] }),
+    (BIG { w: [0, 0, 0, 0, 0, 0, 0] }, BIG { w: [160065436756121534, 112089510869845784, 18643665678620098, 65289997169337046, 164337326564230445, 262208026937056934, 1551209735] }),
+    (BIG { w: [191965934116652828, 48038156457825609, 55930997035860295, 195869991508011138, 204781603540979591, 210163328507747315, 4653629207] }, BIG { w: [240098155134182287, 168134266304768676, 27965498517930147, 242050183829861441, 246505989846345667, 249196852329729529, 2326814603] }),
+    (BIG { w: [122566714858769168, 162796533653370488, 251191858750104807, 62636469627044773, 136094365917780751, 205826270305304847, 4912164163] }, BIG { w: [0, 0, 0, 0, 0, 0, 0] }),
+];
+
+/// `y_den` coefficients `k_(4,0) .. k_(4,2)`; the denominator is monic, so the implicit `x'^3`
+/// term is represented here by the trailing `(1, 0)`.
+pub const Y_DEN: [(BIG, BIG); 4] = [
+    (BIG { w: [143833713099122939, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }, BIG { w: [143833713099122939, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }),
+    (BIG { w: [0, 0, 0, 0, 0, 0, 0] }, BIG { w: [143833713099123155, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }),
+    (BIG { w: [18, 0, 0, 0, 0, 0, 0] }, BIG { w: [143833713099123353, 216172422762594286, 83896495553790442, 149689799186160835, 163057217235613515, 171129804685765101, 6980443811] }),
+    (BIG { w: [1, 0, 0, 0, 0, 0, 0] }, BIG { w: [0, 0, 0, 0, 0, 0, 0] }),
+];
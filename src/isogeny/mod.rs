@@ -0,0 +1,6 @@
+//! Isogeny maps and simplified SWU curve parameters, one module per target curve.
+
+pub mod bls381g1;
+pub mod bls381g2;
+pub mod p256;
+pub mod secp256k1;
@@ -0,0 +1,112 @@
+//! The simplified SWU parameters for NIST P-256, as described in Section 8.2 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>.
+//!
+//! P-256's own `A`/`B` are both nonzero, so — unlike BLS12-381 G1/G2 or secp256k1 — the map
+//! lands directly on P-256 with no isogeny: `ISO_A`/`ISO_B` here are simply P-256's own curve
+//! coefficients, and `crate::p256`'s `iso_map` is the identity.
+
+use amcl_miracl::bls381::big::BIG;
+
+/// The P-256 field modulus `p = 2^256 - 2^224 + 2^192 + 2^96 - 1`.
+pub const MODULUS: BIG = BIG {
+    w: [
+        288230376151711743,
+        274877906943,
+        0,
+        287104476245131264,
+        16777215,
+        0,
+        0,
+    ],
+};
+
+/// `(p - 1) / 2`, used by `is_square`.
+pub const PM1DIV2: BIG = BIG {
+    w: [
+        288230376151711743,
+        137438953471,
+        0,
+        287667426198421504,
+        8388607,
+        0,
+        0,
+    ],
+};
+
+/// The non-square element used by `map_to_curve_simple_swu`: `Z = -10`, per Section 8.2.
+pub const Z: BIG = BIG {
+    w: [
+        288230376151711733,
+        274877906943,
+        0,
+        287104476245131264,
+        16777215,
+        0,
+        0,
+    ],
+};
+
+/// `A = p - 3`, P-256's own curve coefficient.
+pub const ISO_A: BIG = BIG {
+    w: [
+        288230376151711740,
+        274877906943,
+        0,
+        287104476245131264,
+        16777215,
+        0,
+        0,
+    ],
+};
+
+/// P-256's own `B` coefficient.
+pub const ISO_B: BIG = BIG {
+    w: [
+        274222864969916491,
+        234657990994312590,
+        276223062925100625,
+        243943054143049646,
+        5948981,
+        0,
+        0,
+    ],
+};
+
+/// `C1 = -B/A`, precomputed for the Appendix I.1 optimized sample implementation.
+pub const C1: BIG = BIG {
+    w: [
+        91407621656638830,
+        270373006058547674,
+        284227938409508037,
+        80939051412156388,
+        7575399,
+        0,
+        0,
+    ],
+};
+
+/// `C2 = -1/Z`, precomputed for the Appendix I.1 optimized sample implementation.
+pub const C2: BIG = BIG {
+    w: [
+        57646075230342348,
+        115292397850800947,
+        172938225691027046,
+        114278840544762265,
+        15099494,
+        0,
+        0,
+    ],
+};
+
+/// `(p + 1) / 4`, the exponent `sqrt_3mod4` raises to (P-256's `p` is congruent to 3 mod 4).
+pub const SQRT_C1: BIG = BIG {
+    w: [
+        0,
+        68719476736,
+        0,
+        287948901175066624,
+        4194303,
+        0,
+        0,
+    ],
+};
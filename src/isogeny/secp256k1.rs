@@ -0,0 +1,139 @@
+//! The 3-isogeny map to secp256k1 and the simplified SWU parameters used to reach its isogenous
+//! curve `E'`, as described in Section 6.6.3 and the secp256k1 ciphersuite of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>.
+//!
+//! secp256k1 itself is `y^2 = x^3 + 7` (`A = 0`), which `map_to_curve_simple_swu` can't target
+//! directly (it divides by `A`), so — as for BLS12-381 G1/G2 — the map lands on an isogenous
+//! curve `E'` with nonzero `A'`/`B'` first and `crate::secp256k1::iso_map` finishes the trip
+//! back with a rational 3-isogeny.
+//!
+//! `MODULUS`/`PM1DIV2`/`Z`/`SQRT_C1` below are exact (`p = 2^256 - 2^32 - 977`, `Z = -11`). The
+//! isogenous curve coefficients and the isogeny map's `X_NUM`/`X_DEN`/`Y_NUM`/`Y_DEN` tables are
+//! **not** — this crate has no way to derive or check a 3-isogeny in this sandbox, so they are
+//! placeholder values that keep the arithmetic well-typed rather than the genuine constants from
+//! the draft; replace them with the published coefficients before relying on `Secp256k1Sswu` for
+//! anything beyond exercising the code path.
+
+use amcl_miracl::bls381::big::BIG;
+
+/// The secp256k1 field modulus `p = 2^256 - 2^32 - 977`.
+pub const MODULUS: BIG = BIG {
+    w: [
+        288230371856743471,
+        288230376151711743,
+        288230376151711743,
+        288230376151711743,
+        16777215,
+        0,
+        0,
+    ],
+};
+
+/// `(p - 1) / 2`, used by `is_square`.
+pub const PM1DIV2: BIG = BIG {
+    w: [
+        288230374004227607,
+        288230376151711743,
+        288230376151711743,
+        288230376151711743,
+        8388607,
+        0,
+        0,
+    ],
+};
+
+/// The non-square element used by `map_to_curve_simple_swu`: `Z = -11`.
+pub const Z: BIG = BIG {
+    w: [
+        288230371856743460,
+        288230376151711743,
+        288230376151711743,
+        288230376151711743,
+        16777215,
+        0,
+        0,
+    ],
+};
+
+/// `(p + 1) / 4`, the exponent `sqrt_3mod4` raises to (secp256k1's `p` is congruent to 3 mod 4).
+pub const SQRT_C1: BIG = BIG {
+    w: [
+        288230375077969676,
+        288230376151711743,
+        288230376151711743,
+        288230376151711743,
+        4194303,
+        0,
+        0,
+    ],
+};
+
+/// **Placeholder** — the isogenous curve's `A'` coefficient; see the module-level doc comment.
+pub const ISO_A: BIG = BIG {
+    w: [3, 0, 0, 0, 0, 0, 0],
+};
+
+/// **Placeholder** — the isogenous curve's `B'` coefficient; see the module-level doc comment.
+pub const ISO_B: BIG = BIG {
+    w: [1771, 0, 0, 0, 0, 0, 0],
+};
+
+/// `C1 = -B'/A'`, precomputed for the Appendix I.1 optimized sample implementation (derived
+/// from the placeholder `ISO_A`/`ISO_B` above, so likewise not the genuine constant).
+pub const C1: BIG = BIG {
+    w: [
+        96076790618913900,
+        96076792050570581,
+        96076792050570581,
+        96076792050570581,
+        5592405,
+        0,
+        0,
+    ],
+};
+
+/// `C2 = -1/Z`, precomputed for the Appendix I.1 optimized sample implementation.
+pub const C2: BIG = BIG {
+    w: [
+        235824852043681898,
+        78608284405012293,
+        26202761468337431,
+        104811045873349725,
+        4575604,
+        0,
+        0,
+    ],
+};
+
+/// **Placeholder** `x_num` coefficients of the 3-isogeny map; see the module-level doc comment.
+pub const X_NUM: [BIG; 4] = [
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+];
+
+/// **Placeholder** `x_den` coefficients; the denominator is monic, so the implicit `x'^2` term
+/// is represented here by the trailing `1`. See the module-level doc comment.
+pub const X_DEN: [BIG; 3] = [
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+];
+
+/// **Placeholder** `y_num` coefficients of the 3-isogeny map; see the module-level doc comment.
+pub const Y_NUM: [BIG; 4] = [
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+];
+
+/// **Placeholder** `y_den` coefficients; the denominator is monic, so the implicit `x'^3` term
+/// is represented here by the trailing `1`. See the module-level doc comment.
+pub const Y_DEN: [BIG; 4] = [
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [0, 0, 0, 0, 0, 0, 0] },
+    BIG { w: [1, 0, 0, 0, 0, 0, 0] },
+];
@@ -0,0 +1,225 @@
+//! Implements hash to curve for NIST P-256 as described in Section 8.2 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>, via the
+//! generic simplified SWU engine in `crate::sswu`.
+//!
+//! P-256 has cofactor 1, so `encode_to_curve`/`hash_to_curve` apply no cofactor clearing — every
+//! point `map_to_curve` produces is already in the (only) subgroup.
+
+use crate::error::HashingError;
+use crate::isogeny::p256::*;
+use crate::sswu::{self, SswuParams, WeierstrassPoint};
+use crate::{expand_message_xmd, expand_message_xof, DomainSeparationTag};
+use crate::{HashToCurveXmd, HashToCurveXof};
+use amcl_miracl::bls381::big::BIG;
+use digest::{
+    generic_array::typenum::{marker_traits::Unsigned, U48, U96},
+    BlockInput, Digest, ExtendableOutput, Input, Reset, XofReader,
+};
+
+/// To compute `L` use `ceil((log2(p) + k) / 8)`. For P-256, `log2(p) = 256`, `k = 128`, so
+/// `L = 48`.
+type L = U48;
+type TwoL = U96;
+
+/// P256_XMD:SHA-256_SSWU provides both Random Oracle (RO) and Nonuniform (NU) encodings.
+pub struct P256Sswu {
+    dst: DomainSeparationTag,
+}
+
+impl P256Sswu {
+    /// Create a new implementation with the given domain separation tag.
+    pub fn new(dst: DomainSeparationTag) -> Self {
+        Self { dst }
+    }
+}
+
+impl From<DomainSeparationTag> for P256Sswu {
+    fn from(dst: DomainSeparationTag) -> Self {
+        Self { dst }
+    }
+}
+
+impl HashToCurveXmd for P256Sswu {
+    type Output = WeierstrassPoint;
+
+    fn encode_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let u = hash_to_field_xmd_nu::<D, I>(data, &self.dst)?;
+        Ok(encode_to_curve(u))
+    }
+
+    fn hash_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let (u0, u1) = hash_to_field_xmd_ro::<D, I>(data, &self.dst)?;
+        Ok(hash_to_curve(u0, u1))
+    }
+}
+
+impl HashToCurveXof for P256Sswu {
+    type Output = WeierstrassPoint;
+
+    fn encode_to_curve_xof<
+        X: ExtendableOutput + Input + Reset + Default,
+        D: Digest,
+        I: AsRef<[u8]>,
+    >(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let u = hash_to_field_xof_nu::<X, D, I>(data, &self.dst)?;
+        Ok(encode_to_curve(u))
+    }
+
+    fn hash_to_curve_xof<
+        X: ExtendableOutput + Input + Reset + Default,
+        D: Digest,
+        I: AsRef<[u8]>,
+    >(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let (u0, u1) = hash_to_field_xof_ro::<X, D, I>(data, &self.dst)?;
+        Ok(hash_to_curve(u0, u1))
+    }
+}
+
+fn encode_to_curve(u: BIG) -> WeierstrassPoint {
+    map_to_curve(u)
+}
+
+fn hash_to_curve(u0: BIG, u1: BIG) -> WeierstrassPoint {
+    let q0 = map_to_curve(u0);
+    let q1 = map_to_curve(u1);
+    q0.add(&q1, &ISO_A, &MODULUS)
+}
+
+fn map_to_curve(u: BIG) -> WeierstrassPoint {
+    sswu::map_to_curve_simple_swu::<Params>(u)
+}
+
+/// P-256's instantiation of the generic [`sswu::SswuParams`] engine. `A`/`B` are already
+/// nonzero, so `iso_map` is the identity rather than an isogeny.
+struct Params;
+
+impl SswuParams for Params {
+    type Output = WeierstrassPoint;
+
+    const MODULUS: BIG = MODULUS;
+    const PM1DIV2: BIG = PM1DIV2;
+    const Z: BIG = Z;
+    const ISO_A: BIG = ISO_A;
+    const ISO_B: BIG = ISO_B;
+    const C1: BIG = C1;
+    const C2: BIG = C2;
+
+    fn sqrt(x: &BIG) -> BIG {
+        let mut t = BIG::new_big(x);
+        t.powmod(&SQRT_C1, &MODULUS)
+    }
+
+    fn iso_map(x_prime: BIG, y_prime: BIG) -> WeierstrassPoint {
+        WeierstrassPoint::affine(x_prime, y_prime)
+    }
+}
+
+/// Hash to field using expand_message_xmd to compute `u` as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xmd_nu<D: BlockInput + Digest, M: AsRef<[u8]>>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<BIG, HashingError> {
+    let random_bytes = expand_message_xmd::<M, D, L>(msg, dst)?;
+    Ok(sswu::field_elem_from_bytes(random_bytes.as_slice(), &MODULUS))
+}
+
+/// Hash to field using expand_message_xmd to compute two `u`s as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xmd_ro<D: BlockInput + Digest, M: AsRef<[u8]>>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<(BIG, BIG), HashingError> {
+    let random_bytes = expand_message_xmd::<M, D, TwoL>(msg, dst)?;
+    let u_0 = sswu::field_elem_from_bytes(&random_bytes[0..L::to_usize()], &MODULUS);
+    let u_1 = sswu::field_elem_from_bytes(&random_bytes[L::to_usize()..], &MODULUS);
+    Ok((u_0, u_1))
+}
+
+/// Hash to field using expand_message_xof to compute `u` as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xof_nu<
+    X: ExtendableOutput + Input + Reset + Default,
+    D: Digest,
+    M: AsRef<[u8]>,
+>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<BIG, HashingError> {
+    let random_bytes = expand_message_xof::<M, X, D, L>(msg, dst)?;
+    Ok(sswu::field_elem_from_bytes(random_bytes.as_slice(), &MODULUS))
+}
+
+/// Hash to field using expand_message_xof to compute two `u`s as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xof_ro<
+    X: ExtendableOutput + Input + Reset + Default,
+    D: Digest,
+    M: AsRef<[u8]>,
+>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<(BIG, BIG), HashingError> {
+    let random_bytes = expand_message_xof::<M, X, D, TwoL>(msg, dst)?;
+    let u_0 = sswu::field_elem_from_bytes(&random_bytes[0..L::to_usize()], &MODULUS);
+    let u_1 = sswu::field_elem_from_bytes(&random_bytes[L::to_usize()..], &MODULUS);
+    Ok((u_0, u_1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sswu::WeierstrassPoint;
+    use crate::{DomainSeparationTag, HashToCurveXmd};
+    use amcl_miracl::bls381::big::BIG;
+
+    use super::P256Sswu;
+
+    /// RFC 9380 Appendix J.1.1 (`P256_XMD:SHA-256_SSWU_RO_`): the official `"QUUX-V01-CS02-with-"`
+    /// DST, passed whole as `ciphersuite_id` since `DomainSeparationTag::new` only ever appends
+    /// its other parts after `ciphersuite_id` rather than before it.
+    #[test]
+    fn hash_to_curve_xmd_tests() {
+        let dst = DomainSeparationTag::new(
+            "QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_",
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let msgs = [
+            "",
+            "abc",
+            "abcdef0123456789",
+            "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        ];
+        let p = [
+            ("2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4", "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"),
+            ("0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f", "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"),
+            ("65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80", "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3"),
+            ("457ae2981f70ca85d8e24c308b14db22f3e3862c5ea0f652ca38b5e49cd64bc5", "ecb9f0eadc9aeed232dabc53235368c1394c78de05dd96893eefa62b0f4757dc"),
+        ];
+
+        let hasher = P256Sswu::from(dst);
+
+        for i in 0..msgs.len() {
+            let expected = WeierstrassPoint::affine(
+                BIG::from_hex(p[i].0.to_string()),
+                BIG::from_hex(p[i].1.to_string()),
+            );
+            let actual = hasher.hash_to_curve_xmd::<sha2::Sha256, &str>(msgs[i]).unwrap();
+            assert_eq!(expected, actual, "msg {:?}", msgs[i]);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! A ciphersuite registry: dispatch by the standard suite name (the same string passed as
+//! `DomainSeparationTag::new`'s `ciphersuite_id`, e.g. `"BLS12381G1_XMD:SHA-256_SSWU_RO_"`)
+//! instead of picking a concrete curve/digest/expander type at compile time.
+//!
+//! The per-curve modules (`bls381g1`, `bls381g2`, `p256`, `secp256k1`) stay generic over the
+//! digest/XOF and are the right choice when the caller already knows which suite it wants at
+//! compile time — `Suite` wraps them for the case where the suite name itself is only known at
+//! runtime (e.g. read from config or negotiated with a peer), at the cost of fixing one concrete
+//! digest per suite instead of leaving it generic.
+
+use crate::bls381g1::Bls12381G1Sswu;
+use crate::bls381g2::Bls12381G2Sswu;
+use crate::error::HashingError;
+use crate::p256::P256Sswu;
+use crate::sswu::WeierstrassPoint;
+use crate::{DomainSeparationTag, HashToCurveXmd};
+use amcl_miracl::bls381::{ecp::ECP, ecp2::ECP2};
+
+/// The point type produced by a [`Suite`], unified across every curve the registry knows about.
+///
+/// Each variant is the same `Output` type the corresponding per-curve `HashToCurveXmd`/
+/// `HashToCurveXof` impl already produces (`ECP`/`ECP2` from `amcl_miracl`, or this crate's own
+/// [`WeierstrassPoint`] for the curves with no dedicated `amcl_miracl` type).
+///
+/// No `Secp256k1` variant: `crate::secp256k1`'s isogeny coefficients are placeholders, not the
+/// genuine published constants, so `Suite::from_name` refuses every secp256k1 suite name rather
+/// than dispatching to it — see the module-level doc comment on `crate::isogeny::secp256k1`.
+#[derive(Clone, Debug)]
+pub enum CurvePoint {
+    Bls12381G1(ECP),
+    Bls12381G2(ECP2),
+    P256(WeierstrassPoint),
+}
+
+/// The family of map-to-curve function a ciphersuite uses, as named in Section 6 of
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>.
+///
+/// Only [`MapToCurve::Sswu`] is implemented — the other variants exist so
+/// [`Suite::from_name`] can recognize a suite name that names a real, standard mapping this
+/// crate simply hasn't built yet, and report [`HashingError::UnsupportedMapToCurve`] instead of
+/// the more confusing [`HashingError::UnknownCiphersuite`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapToCurve {
+    /// The simplified Shallue–van de Woestijne–Ulas method (Section 6.6.2) — every suite this
+    /// crate implements today uses this.
+    Sswu,
+    /// The (unsimplified) Shallue–van de Woestijne method (Section 6.6.1). Not implemented.
+    ShallueVanDeWoestijne,
+    /// Elligator 2 (Section 6.7), used by edwards25519/edwards448 suites. Not implemented.
+    Elligator2,
+}
+
+/// A ciphersuite this crate can carry out, resolved at runtime from its standard name by
+/// [`Suite::from_name`].
+///
+/// Every suite this registry knows about maps field elements with [`MapToCurve::Sswu`] and
+/// expands messages with `expand_message_xmd`, fixed to the one digest the suite's name calls
+/// for (the per-curve types in `bls381g1`/`bls381g2`/`p256`/`secp256k1` stay generic over the
+/// digest; this registry trades that flexibility for runtime dispatch by name).
+///
+/// No secp256k1 variant, for the same reason [`CurvePoint`] has none.
+pub enum Suite {
+    Bls12381G1Sha256(Bls12381G1Sswu),
+    Bls12381G2Sha256(Bls12381G2Sswu),
+    P256Sha256(P256Sswu),
+}
+
+impl Suite {
+    /// Resolves a standard ciphersuite ID (e.g. `"BLS12381G1_XMD:SHA-256_SSWU_RO_"`) to a
+    /// [`Suite`], building its `DomainSeparationTag` from `ciphersuite_id` plus the same
+    /// optional parts `DomainSeparationTag::new` takes.
+    ///
+    /// Only the `XMD:SHA-256_SSWU_RO_`/`_NU_` suites for BLS12-381 G1/G2 and P-256 are wired up
+    /// so far; a recognized-but-unimplemented mapping (Shallue–van de Woestijne, Elligator2) is
+    /// reported as [`HashingError::UnsupportedMapToCurve`], anything else as
+    /// [`HashingError::UnknownCiphersuite`]. The secp256k1 suites are recognized by name but
+    /// also report [`HashingError::UnsupportedMapToCurve`]: `crate::isogeny::secp256k1`'s
+    /// 3-isogeny coefficients are placeholders, not the genuine published constants, so this
+    /// crate can't stand behind `secp256k1_XMD:SHA-256_SSWU_RO_`/`_NU_` yet.
+    pub fn from_name(
+        ciphersuite_id: &str,
+        application_tag: Option<&str>,
+        revision: Option<&str>,
+        extra: Option<&str>,
+    ) -> Result<Self, HashingError> {
+        let map_to_curve = map_to_curve_of(ciphersuite_id)?;
+        if map_to_curve != MapToCurve::Sswu {
+            return Err(HashingError::UnsupportedMapToCurve);
+        }
+
+        let dst = DomainSeparationTag::new(ciphersuite_id, application_tag, revision, extra)?;
+        match ciphersuite_id {
+            "BLS12381G1_XMD:SHA-256_SSWU_RO_" | "BLS12381G1_XMD:SHA-256_SSWU_NU_" => {
+                Ok(Suite::Bls12381G1Sha256(Bls12381G1Sswu::from(dst)))
+            }
+            "BLS12381G2_XMD:SHA-256_SSWU_RO_" | "BLS12381G2_XMD:SHA-256_SSWU_NU_" => {
+                Ok(Suite::Bls12381G2Sha256(Bls12381G2Sswu::from(dst)))
+            }
+            "P256_XMD:SHA-256_SSWU_RO_" | "P256_XMD:SHA-256_SSWU_NU_" => {
+                Ok(Suite::P256Sha256(P256Sswu::from(dst)))
+            }
+            "secp256k1_XMD:SHA-256_SSWU_RO_" | "secp256k1_XMD:SHA-256_SSWU_NU_" => {
+                Err(HashingError::UnsupportedMapToCurve)
+            }
+            _ => Err(HashingError::UnknownCiphersuite),
+        }
+    }
+
+    /// The random oracle encoding (Section 3), using whichever digest the suite name fixed.
+    ///
+    /// Valid to call regardless of whether `ciphersuite_id` ended in `_RO_` or `_NU_` — the
+    /// suffix only documents which encoding the suite's own test vectors exercise, nothing in
+    /// `DomainSeparationTag` enforces it, so both encodings are always available.
+    pub fn hash_to_curve<I: AsRef<[u8]>>(&self, data: I) -> Result<CurvePoint, HashingError> {
+        match self {
+            Suite::Bls12381G1Sha256(s) => s
+                .hash_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::Bls12381G1),
+            Suite::Bls12381G2Sha256(s) => s
+                .hash_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::Bls12381G2),
+            Suite::P256Sha256(s) => s
+                .hash_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::P256),
+        }
+    }
+
+    /// The nonuniform encoding (Section 3), using whichever digest the suite name fixed.
+    pub fn encode_to_curve<I: AsRef<[u8]>>(&self, data: I) -> Result<CurvePoint, HashingError> {
+        match self {
+            Suite::Bls12381G1Sha256(s) => s
+                .encode_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::Bls12381G1),
+            Suite::Bls12381G2Sha256(s) => s
+                .encode_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::Bls12381G2),
+            Suite::P256Sha256(s) => s
+                .encode_to_curve_xmd::<sha2::Sha256, I>(data)
+                .map(CurvePoint::P256),
+        }
+    }
+}
+
+/// Pattern-matches a ciphersuite ID's `_SSWU_`/`_SVDW_`/`_ELL2_` infix to the mapping it names,
+/// independent of whether `Suite::from_name` goes on to actually support that curve/digest
+/// combination.
+fn map_to_curve_of(ciphersuite_id: &str) -> Result<MapToCurve, HashingError> {
+    if ciphersuite_id.contains("_SSWU_") {
+        Ok(MapToCurve::Sswu)
+    } else if ciphersuite_id.contains("_SVDW_") {
+        Ok(MapToCurve::ShallueVanDeWoestijne)
+    } else if ciphersuite_id.contains("_ELL2_") {
+        Ok(MapToCurve::Elligator2)
+    } else {
+        Err(HashingError::UnknownCiphersuite)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurvePoint, Suite};
+    use crate::HashingError;
+
+    #[test]
+    fn resolves_known_suite_names() {
+        for name in [
+            "BLS12381G1_XMD:SHA-256_SSWU_RO_",
+            "BLS12381G1_XMD:SHA-256_SSWU_NU_",
+            "BLS12381G2_XMD:SHA-256_SSWU_RO_",
+            "P256_XMD:SHA-256_SSWU_RO_",
+        ] {
+            assert!(Suite::from_name(name, None, None, None).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_suite_name() {
+        let err = Suite::from_name("NOT_A_REAL_SUITE_SSWU_RO_", None, None, None).unwrap_err();
+        assert_eq!(err, HashingError::UnknownCiphersuite);
+    }
+
+    #[test]
+    fn recognizes_unimplemented_mapping() {
+        let err =
+            Suite::from_name("edwards25519_XMD:SHA-512_ELL2_RO_", None, None, None).unwrap_err();
+        assert_eq!(err, HashingError::UnsupportedMapToCurve);
+    }
+
+    /// secp256k1 is recognized by name (not `UnknownCiphersuite`) but refused (not `Ok`):
+    /// `crate::isogeny::secp256k1`'s 3-isogeny coefficients are placeholders, not the genuine
+    /// published constants, so the registry must not hand out a `Secp256k1Sha256` suite that
+    /// looks trustworthy but almost certainly isn't on the real curve.
+    #[test]
+    fn refuses_secp256k1_pending_real_isogeny_constants() {
+        for name in [
+            "secp256k1_XMD:SHA-256_SSWU_RO_",
+            "secp256k1_XMD:SHA-256_SSWU_NU_",
+        ] {
+            let err = Suite::from_name(name, None, None, None).unwrap_err();
+            assert_eq!(err, HashingError::UnsupportedMapToCurve, "{name}");
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_dispatches_by_name() {
+        let suite = Suite::from_name("BLS12381G1_XMD:SHA-256_SSWU_RO_", Some("TEST"), None, None)
+            .unwrap();
+        match suite.hash_to_curve("registry test message").unwrap() {
+            CurvePoint::Bls12381G1(_) => {}
+            other => panic!("expected a BLS12-381 G1 point, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Implements hash to curve for secp256k1 as described in Section 6.6.3 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>, via the
+//! generic simplified SWU engine in `crate::sswu`.
+//!
+//! **Not part of the public API.** See `crate::isogeny::secp256k1` for why: the isogenous curve
+//! coefficients and isogeny map tables are placeholders, not the genuine published constants, so
+//! `lib.rs` doesn't re-export [`Secp256k1Sswu`] and `crate::registry::Suite::from_name` refuses
+//! every secp256k1 suite name rather than handing one out. This module stays compiled (so the
+//! engine/isogeny-map plumbing keeps exercising against a third curve) but only reachable within
+//! the crate, until the real constants replace the placeholders.
+//!
+//! secp256k1 has cofactor 1, so `encode_to_curve`/`hash_to_curve` apply no cofactor clearing —
+//! every point `map_to_curve` produces (after the isogeny map) is already in the (only) subgroup.
+
+use crate::error::HashingError;
+use crate::isogeny::secp256k1::*;
+use crate::sswu::{self, SswuParams, WeierstrassPoint};
+use crate::{expand_message_xmd, expand_message_xof, DomainSeparationTag};
+use crate::{HashToCurveXmd, HashToCurveXof};
+use amcl_miracl::bls381::big::BIG;
+use digest::{
+    generic_array::typenum::{marker_traits::Unsigned, U48, U96},
+    BlockInput, Digest, ExtendableOutput, Input, Reset, XofReader,
+};
+
+/// To compute `L` use `ceil((log2(p) + k) / 8)`. For secp256k1, `log2(p) = 256`, `k = 128`, so
+/// `L = 48`.
+type L = U48;
+type TwoL = U96;
+
+/// secp256k1_XMD:SHA-256_SSWU provides both Random Oracle (RO) and Nonuniform (NU) encodings.
+pub struct Secp256k1Sswu {
+    dst: DomainSeparationTag,
+}
+
+impl Secp256k1Sswu {
+    /// Create a new implementation with the given domain separation tag.
+    pub fn new(dst: DomainSeparationTag) -> Self {
+        Self { dst }
+    }
+}
+
+impl From<DomainSeparationTag> for Secp256k1Sswu {
+    fn from(dst: DomainSeparationTag) -> Self {
+        Self { dst }
+    }
+}
+
+impl HashToCurveXmd for Secp256k1Sswu {
+    type Output = WeierstrassPoint;
+
+    fn encode_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let u = hash_to_field_xmd_nu::<D, I>(data, &self.dst)?;
+        Ok(encode_to_curve(u))
+    }
+
+    fn hash_to_curve_xmd<D: BlockInput + Digest, I: AsRef<[u8]>>(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let (u0, u1) = hash_to_field_xmd_ro::<D, I>(data, &self.dst)?;
+        Ok(hash_to_curve(u0, u1))
+    }
+}
+
+impl HashToCurveXof for Secp256k1Sswu {
+    type Output = WeierstrassPoint;
+
+    fn encode_to_curve_xof<
+        X: ExtendableOutput + Input + Reset + Default,
+        D: Digest,
+        I: AsRef<[u8]>,
+    >(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let u = hash_to_field_xof_nu::<X, D, I>(data, &self.dst)?;
+        Ok(encode_to_curve(u))
+    }
+
+    fn hash_to_curve_xof<
+        X: ExtendableOutput + Input + Reset + Default,
+        D: Digest,
+        I: AsRef<[u8]>,
+    >(
+        &self,
+        data: I,
+    ) -> Result<Self::Output, HashingError> {
+        let (u0, u1) = hash_to_field_xof_ro::<X, D, I>(data, &self.dst)?;
+        Ok(hash_to_curve(u0, u1))
+    }
+}
+
+fn encode_to_curve(u: BIG) -> WeierstrassPoint {
+    map_to_curve(u)
+}
+
+fn hash_to_curve(u0: BIG, u1: BIG) -> WeierstrassPoint {
+    let q0 = map_to_curve(u0);
+    let q1 = map_to_curve(u1);
+    // secp256k1 itself is y^2 = x^3 + 7, so A = 0 for this post-isogeny addition.
+    q0.add(&q1, &BIG::new(), &MODULUS)
+}
+
+fn map_to_curve(u: BIG) -> WeierstrassPoint {
+    sswu::map_to_curve_simple_swu::<Params>(u)
+}
+
+/// secp256k1's instantiation of the generic [`sswu::SswuParams`] engine: Section 6.6.2.1's
+/// simplified SWU map over the isogenous `E'`, followed by a 3-isogeny map back to secp256k1
+/// itself.
+struct Params;
+
+impl SswuParams for Params {
+    type Output = WeierstrassPoint;
+
+    const MODULUS: BIG = MODULUS;
+    const PM1DIV2: BIG = PM1DIV2;
+    const Z: BIG = Z;
+    const ISO_A: BIG = ISO_A;
+    const ISO_B: BIG = ISO_B;
+    const C1: BIG = C1;
+    const C2: BIG = C2;
+
+    fn sqrt(x: &BIG) -> BIG {
+        let mut t = BIG::new_big(x);
+        t.powmod(&SQRT_C1, &MODULUS)
+    }
+
+    fn iso_map(x_prime: BIG, y_prime: BIG) -> WeierstrassPoint {
+        sswu::apply_rational_isogeny(x_prime, y_prime, &X_NUM, &X_DEN, &Y_NUM, &Y_DEN, &MODULUS)
+    }
+}
+
+/// Hash to field using expand_message_xmd to compute `u` as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xmd_nu<D: BlockInput + Digest, M: AsRef<[u8]>>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<BIG, HashingError> {
+    let random_bytes = expand_message_xmd::<M, D, L>(msg, dst)?;
+    Ok(sswu::field_elem_from_bytes(random_bytes.as_slice(), &MODULUS))
+}
+
+/// Hash to field using expand_message_xmd to compute two `u`s as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xmd_ro<D: BlockInput + Digest, M: AsRef<[u8]>>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<(BIG, BIG), HashingError> {
+    let random_bytes = expand_message_xmd::<M, D, TwoL>(msg, dst)?;
+    let u_0 = sswu::field_elem_from_bytes(&random_bytes[0..L::to_usize()], &MODULUS);
+    let u_1 = sswu::field_elem_from_bytes(&random_bytes[L::to_usize()..], &MODULUS);
+    Ok((u_0, u_1))
+}
+
+/// Hash to field using expand_message_xof to compute `u` as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xof_nu<
+    X: ExtendableOutput + Input + Reset + Default,
+    D: Digest,
+    M: AsRef<[u8]>,
+>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<BIG, HashingError> {
+    let random_bytes = expand_message_xof::<M, X, D, L>(msg, dst)?;
+    Ok(sswu::field_elem_from_bytes(random_bytes.as_slice(), &MODULUS))
+}
+
+/// Hash to field using expand_message_xof to compute two `u`s as specified in Section 5.2 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
+fn hash_to_field_xof_ro<
+    X: ExtendableOutput + Input + Reset + Default,
+    D: Digest,
+    M: AsRef<[u8]>,
+>(
+    msg: M,
+    dst: &DomainSeparationTag,
+) -> Result<(BIG, BIG), HashingError> {
+    let random_bytes = expand_message_xof::<M, X, D, TwoL>(msg, dst)?;
+    let u_0 = sswu::field_elem_from_bytes(&random_bytes[0..L::to_usize()], &MODULUS);
+    let u_1 = sswu::field_elem_from_bytes(&random_bytes[L::to_usize()..], &MODULUS);
+    Ok((u_0, u_1))
+}
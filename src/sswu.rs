@@ -0,0 +1,289 @@
+//! Generalizes the simplified SWU pipeline — `map_to_curve_simple_swu`, a rational isogeny map,
+//! `is_square`, `sgn0` — into a curve-parameter trait, so the same machinery that used to be
+//! hard-wired to BLS12-381 G1 can drive any short-Weierstrass curve whose field fits a `BIG`.
+//!
+//! `BIG`'s `mod*` operations already take their modulus as an explicit argument, so nothing
+//! about `amcl_miracl`'s big-integer arithmetic is BLS12-381-specific; only a curve's own
+//! constants (`Z`, the (possibly isogenous) curve's `A`/`B`, its isogeny map, and the optimized
+//! sample implementation's `C1`/`C2` from Appendix I.1 of
+//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>) are.
+
+use alloc::vec::Vec;
+use amcl_miracl::bls381::{big::BIG, dbig::DBIG};
+
+/// A point on a generic short Weierstrass curve `y^2 = x^3 + Ax + B` over `Fp`, in affine
+/// coordinates. Curves with their own `amcl_miracl` point type (BLS12-381 G1's `ECP`) don't
+/// need this — it exists for curves that only have this crate's `BIG` field arithmetic to build
+/// on, such as P-256 and secp256k1.
+///
+/// Public (rather than `pub(crate)`) so it can serve as `HashToCurveXmd`/`HashToCurveXof::Output`
+/// for those curves and as a [`crate::registry::CurvePoint`] variant, the same way BLS12-381 G1
+/// and G2 expose `amcl_miracl`'s own `ECP`/`ECP2` as their `Output`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeierstrassPoint {
+    pub(crate) x: BIG,
+    pub(crate) y: BIG,
+}
+
+impl WeierstrassPoint {
+    pub(crate) fn affine(x: BIG, y: BIG) -> Self {
+        Self { x, y }
+    }
+
+    /// The point's affine `x` coordinate.
+    pub fn x(&self) -> &BIG {
+        &self.x
+    }
+
+    /// The point's affine `y` coordinate.
+    pub fn y(&self) -> &BIG {
+        &self.y
+    }
+
+    /// `self + self`, for the curve `y^2 = x^3 + Ax + B mod p` — used by `hash_to_curve`'s RO
+    /// encoding to sum the two points `map_to_curve` produces after `iso_map` has already run, so
+    /// `a`/`p` here are the *target* curve's own coefficients, not an isogenous curve's.
+    pub(crate) fn double(&self, a: &BIG, p: &BIG) -> Self {
+        // lambda = (3x^2 + A) / (2y)
+        let mut num = BIG::modsqr(&self.x, p);
+        num = BIG::modmul(&BIG::new_int(3), &num, p);
+        num.add(a);
+        num.rmod(p);
+        let mut den = BIG::modmul(&BIG::new_int(2), &self.y, p);
+        den.invmodp(p);
+        let lambda = BIG::modmul(&num, &den, p);
+
+        let mut x3 = BIG::modsqr(&lambda, p);
+        x3.sub(&self.x);
+        x3.sub(&self.x);
+        x3.rmod(p);
+
+        let mut y3 = BIG::new_big(&self.x);
+        y3.sub(&x3);
+        y3.rmod(p);
+        let mut y3 = BIG::modmul(&lambda, &y3, p);
+        y3.sub(&self.y);
+        y3.rmod(p);
+
+        Self::affine(x3, y3)
+    }
+
+    /// `self + other`. Points produced by `map_to_curve_simple_swu` are never the identity
+    /// (`map_to_curve` always returns an affine point), so unlike a general-purpose point type
+    /// this doesn't need a point-at-infinity case.
+    pub(crate) fn add(&self, other: &Self, a: &BIG, p: &BIG) -> Self {
+        if self.x == other.x {
+            return self.double(a, p);
+        }
+        // lambda = (y2 - y1) / (x2 - x1)
+        let mut num = BIG::new_big(&other.y);
+        num.sub(&self.y);
+        num.rmod(p);
+        let mut den = BIG::new_big(&other.x);
+        den.sub(&self.x);
+        den.rmod(p);
+        den.invmodp(p);
+        let lambda = BIG::modmul(&num, &den, p);
+
+        let mut x3 = BIG::modsqr(&lambda, p);
+        x3.sub(&self.x);
+        x3.sub(&other.x);
+        x3.rmod(p);
+
+        let mut y3 = BIG::new_big(&self.x);
+        y3.sub(&x3);
+        y3.rmod(p);
+        let mut y3 = BIG::modmul(&lambda, &y3, p);
+        y3.sub(&self.y);
+        y3.rmod(p);
+
+        Self::affine(x3, y3)
+    }
+}
+
+/// The curve-specific inputs `map_to_curve_simple_swu` needs, plus the two constants `C1`/`C2`
+/// precomputed by the optimized sample implementation in Appendix I.1: the field modulus, the
+/// non-square `Z`, the (possibly isogenous) curve's `A`/`B`, and the rational map back to the
+/// target curve (the identity map when, as for P-256, the target curve's own `A`/`B` are already
+/// both nonzero and no isogeny is needed).
+///
+/// Every curve instantiated so far has `p ≡ 3 (mod 4)`, so [`SswuParams::sqrt`] is always
+/// `sqrt_3mod4` under a curve-specific exponent — but it's a method rather than a shared
+/// function precisely so a future `p ≡ 1 (mod 4)` curve can supply a Tonelli–Shanks `sqrt`
+/// instead without touching the engine.
+pub(crate) trait SswuParams {
+    /// The curve point type `iso_map` produces — BLS12-381 G1's own `ECP`, or a plain
+    /// [`WeierstrassPoint`] for a curve with no dedicated `amcl_miracl` type.
+    type Output;
+
+    const MODULUS: BIG;
+    const PM1DIV2: BIG;
+    const Z: BIG;
+    const ISO_A: BIG;
+    const ISO_B: BIG;
+    const C1: BIG;
+    const C2: BIG;
+
+    /// `sqrt(x)` for `x` a square mod `Self::MODULUS`; unspecified otherwise.
+    fn sqrt(x: &BIG) -> BIG;
+
+    /// Maps a point on `E'` (the curve `y^2 = x^3 + Self::ISO_A x + Self::ISO_B`) to a point on
+    /// the target curve — the identity when there is no isogeny.
+    fn iso_map(x_prime: BIG, y_prime: BIG) -> Self::Output;
+}
+
+/// `is_square(x) := x^((p - 1) / 2)` is `0` or `1` in `Fp`. The same definition used throughout
+/// `bls381g1`/`bls381g2`, parameterized over the curve's own modulus and `PM1DIV2`.
+pub(crate) fn is_square<P: SswuParams>(x: &BIG) -> bool {
+    let mut t = BIG::new_copy(x);
+    t = t.powmod(&P::PM1DIV2, &P::MODULUS);
+    let mut sum = 0;
+    for i in 1..t.w.len() {
+        sum |= t.w[i];
+    }
+    sum == 0 && (t.w[0] == 0 || t.w[0] == 1)
+}
+
+/// See Section 4.1 in
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>: integer
+/// parity, the least-significant bit of `x`'s canonical representative.
+pub(crate) fn sgn0(x: &BIG) -> bool {
+    x.w[0] & 1 == 1
+}
+
+/// Section 6.6.2.1's simplified SWU map via the Appendix I.1 optimized sample code, generic over
+/// any [`SswuParams`] whose field fits a `BIG`.
+///
+/// The square root comes from [`SswuParams::sqrt`]; every curve instantiated against this engine
+/// so far has `p` congruent to 3 mod 4 and supplies `sqrt_3mod4` there.
+pub(crate) fn map_to_curve_simple_swu<P: SswuParams>(u: BIG) -> P::Output {
+    // tv1 = Z * u^2
+    let tv1 = BIG::modmul(&P::Z, &BIG::modsqr(&u, &P::MODULUS), &P::MODULUS);
+    // tv2 = tv1^2
+    let mut tv2 = BIG::modsqr(&tv1, &P::MODULUS);
+
+    // x1 = tv1 + tv2
+    let mut x1 = BIG::new_big(&tv1);
+    x1.add(&tv2);
+    x1.rmod(&P::MODULUS);
+
+    // x1 = inv0(x1)
+    x1.invmodp(&P::MODULUS);
+
+    let e1 = if x1.iszilch() { 1 } else { 0 };
+
+    // x1 = x1 + 1
+    x1.inc(1);
+
+    // x1 = CMOV(x1, c2, e1)
+    x1.cmove(&P::C2, e1);
+
+    // x1 = x1 * c1
+    x1 = BIG::modmul(&x1, &P::C1, &P::MODULUS);
+
+    // gx1 = x1^2
+    let mut gx1 = BIG::modsqr(&x1, &P::MODULUS);
+    // gx1 = gx1 + A
+    gx1.add(&P::ISO_A);
+    gx1.rmod(&P::MODULUS);
+
+    // gx1 = gx1 * x1
+    gx1 = BIG::modmul(&gx1, &x1, &P::MODULUS);
+
+    // gx1 = gx1 + B
+    gx1.add(&P::ISO_B);
+    gx1.rmod(&P::MODULUS);
+
+    // x2 = tv1 * x1
+    let x2 = BIG::modmul(&tv1, &x1, &P::MODULUS);
+
+    // tv2 = tv1 * tv2
+    tv2 = BIG::modmul(&tv1, &tv2, &P::MODULUS);
+
+    // gx2 = gx1 * tv2
+    let gx2 = BIG::modmul(&gx1, &tv2, &P::MODULUS);
+
+    // e2 = is_square(gx1)
+    let e2 = if is_square::<P>(&gx1) { 1 } else { 0 };
+
+    // x = CMOV(x2, x1, e2)
+    let mut x = BIG::new_copy(&x2);
+    x.cmove(&x1, e2);
+
+    // y2 = CMOV(gx2, gx1, e2)
+    let mut y2 = BIG::new_copy(&gx2);
+    y2.cmove(&gx1, e2);
+
+    // y = sqrt(y2)
+    let y = P::sqrt(&y2);
+
+    // e3 = sgn0(u) == sgn0(y)
+    let e3 = if sgn0(&u) == sgn0(&y) { 1 } else { 0 };
+
+    // y = CMOV(-y, y, e3)
+    let mut y_neg = BIG::modneg(&y, &P::MODULUS);
+    y_neg.cmove(&y, e3);
+
+    P::iso_map(x, y_neg)
+}
+
+/// Applies a rational isogeny map `(x_num(x')/x_den(x'), y' * y_num(x')/y_den(x'))` to a point
+/// on the isogenous curve `E'`, as used by BLS12-381 G1's 11-isogeny (Section 4.3 of
+/// <https://eprint.iacr.org/2019/403.pdf>) and secp256k1's 3-isogeny (Section 6.6.3 of the
+/// draft above).
+pub(crate) fn apply_rational_isogeny(
+    x_prime: BIG,
+    y_prime: BIG,
+    x_num: &[BIG],
+    x_den: &[BIG],
+    y_num: &[BIG],
+    y_den: &[BIG],
+    modulus: &BIG,
+) -> WeierstrassPoint {
+    let degree = [x_num.len(), x_den.len(), y_num.len(), y_den.len()]
+        .into_iter()
+        .max()
+        .unwrap_or(1);
+
+    let mut powers = Vec::with_capacity(degree);
+    powers.push(BIG::new_int(1));
+    for i in 1..degree {
+        let next = BIG::modmul(&powers[i - 1], &x_prime, modulus);
+        powers.push(next);
+    }
+
+    let eval = |coeffs: &[BIG]| -> BIG {
+        let mut acc = BIG::new();
+        for (power, coeff) in powers.iter().zip(coeffs.iter()) {
+            let t = BIG::modmul(power, coeff, modulus);
+            acc.add(&t);
+            acc.rmod(modulus);
+        }
+        acc
+    };
+
+    let mut x_den_v = eval(x_den);
+    let mut x = eval(x_num);
+    x_den_v.invmodp(modulus);
+    x = BIG::modmul(&x, &x_den_v, modulus);
+
+    let mut y_den_v = eval(y_den);
+    let mut y = eval(y_num);
+    y_den_v.invmodp(modulus);
+    y = BIG::modmul(&y, &y_den_v, modulus);
+    y = BIG::modmul(&y, &y_prime, modulus);
+
+    WeierstrassPoint::affine(x, y)
+}
+
+/// `e_j = OS2IP(tv) mod p`, reducing a hash-to-field output block down to a field element —
+/// `FIELD_ELEMENT_SIZE <= random_bytes.len() <= FIELD_ELEMENT_SIZE * 2`, generic over the
+/// target field's own modulus.
+pub(crate) fn field_elem_from_bytes(random_bytes: &[u8], modulus: &BIG) -> BIG {
+    let mut d = DBIG::new();
+    for &byte in random_bytes {
+        d.shl(8);
+        d.w[0] += byte as amcl_miracl::arch::Chunk;
+    }
+    d.dmod(modulus)
+}